@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error {
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error {
+            message: error.to_string(),
+        }
+    }
+}
+
+// User-level defaults for create_mod, loaded from config.json in the
+// platform config directory. Unset fields fall back to a built-in default
+// and can still be overridden per-invocation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case", default)]
+pub struct Config {
+    pub group: Option<String>,
+    pub language: Option<String>,
+    pub license: Option<String>,
+    pub minecraft_version: Option<String>,
+    // Keyed by the name they're selectable under with --template <name>.
+    pub templates: HashMap<String, CustomTemplate>,
+    // Used for --publish-to-github. GITHUB_TOKEN takes precedence over this.
+    pub github_token: Option<String>,
+}
+
+// A community or personal scaffold template registered under a short name,
+// carrying the refactor assumptions create_mod needs to adapt it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CustomTemplate {
+    pub source: String,
+    pub language: String,
+    #[serde(default = "default_old_package")]
+    pub old_package: String,
+    #[serde(default = "default_old_mixin_config")]
+    pub old_mixin_config: String,
+}
+
+fn default_old_package() -> String {
+    "net.fabricmc.example".to_string()
+}
+
+fn default_old_mixin_config() -> String {
+    "modid.mixins.json".to_string()
+}
+
+pub fn config_path() -> Result<PathBuf, Error> {
+    dirs::config_dir()
+        .map(|dir| dir.join("new-fabric-mod").join("config.json"))
+        .ok_or_else(|| Error {
+            message: "could not determine the platform config directory".to_string(),
+        })
+}
+
+pub fn load() -> Result<Config, Error> {
+    let path = config_path()?;
+    if !path.exists() {
+        return Ok(Config::default());
+    }
+
+    let contents = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+pub fn json_schema() -> schemars::schema::RootSchema {
+    schemars::schema_for!(Config)
+}
+
+pub fn resolve<T>(cli_arg: Option<T>, config_value: Option<T>, default: T) -> T {
+    cli_arg.or(config_value).unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_round_trips_through_serde() {
+        let mut templates = HashMap::new();
+        templates.insert(
+            "kotlin-dsl".to_string(),
+            CustomTemplate {
+                source: "https://example.com/template".to_string(),
+                language: "kotlin".to_string(),
+                old_package: "net.fabricmc.example".to_string(),
+                old_mixin_config: "modid.mixins.json".to_string(),
+            },
+        );
+
+        let config = Config {
+            group: Some("com.example".to_string()),
+            language: Some("kotlin".to_string()),
+            license: Some("MIT".to_string()),
+            minecraft_version: Some("1.20.1".to_string()),
+            templates,
+            github_token: Some("ghp_example".to_string()),
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        let round_tripped: Config = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(config, round_tripped);
+    }
+
+    #[test]
+    fn test_config_defaults_to_empty() {
+        let config: Config = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn test_custom_template_defaults_refactor_assumptions() {
+        let template: CustomTemplate = serde_json::from_str(
+            r#"{"source": "https://example.com/template", "language": "kotlin"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(template.old_package, "net.fabricmc.example");
+        assert_eq!(template.old_mixin_config, "modid.mixins.json");
+    }
+
+    #[test]
+    fn test_resolve_prefers_cli_arg_over_config_over_default() {
+        assert_eq!(resolve(Some("cli"), Some("config"), "default"), "cli");
+        assert_eq!(resolve(None, Some("config"), "default"), "config");
+        assert_eq!(resolve(None, None, "default"), "default");
+    }
+}
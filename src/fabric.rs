@@ -1,10 +1,99 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use crate::{
     code::{language::Language, refactor},
-    git,
+    config::{self, CustomTemplate},
+    git, github, templates,
 };
 
+// Controls whether create_mod (re-)initializes a git repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vcs {
+    Git,
+    None,
+}
+
+// Sentinel accepted in Versions that resolves a version from Fabric's public
+// meta endpoints instead of a fixed value.
+const LATEST: &str = "latest";
+
+// Leaving a field as None keeps whatever value the template already has;
+// passing LATEST resolves it from Fabric's meta endpoints. The exception is
+// fabric_api_version, for which LATEST also keeps the template's value.
+#[derive(Debug, Clone, Default)]
+pub struct Versions {
+    pub minecraft_version: Option<String>,
+    pub yarn_mappings: Option<String>,
+    pub loader_version: Option<String>,
+    pub fabric_api_version: Option<String>,
+}
+
+const DEFAULT_GROUP: &str = "com.example";
+const DEFAULT_LANGUAGE: &str = "java";
+
+// Fully-resolved inputs to create_mod, after merging config.json's defaults
+// under whatever was passed explicitly. See resolve_options.
+#[derive(Debug, Clone)]
+pub struct ScaffoldOptions {
+    pub language: Language,
+    pub main_class: String,
+    pub license: Option<String>,
+    pub minecraft_version: Option<String>,
+    pub custom_templates: HashMap<String, CustomTemplate>,
+}
+
+// An explicit argument always wins, then the config value, then the
+// built-in default. This is the one place config.json's defaults take
+// effect; create_mod itself only ever sees fully-resolved values.
+pub fn resolve_options(
+    class_name: &str,
+    group: Option<&str>,
+    language: Option<&str>,
+    license: Option<&str>,
+    minecraft_version: Option<&str>,
+    custom_templates: &HashMap<String, CustomTemplate>,
+    config: &config::Config,
+) -> Result<ScaffoldOptions, Error> {
+    let group = config::resolve(
+        group.map(str::to_string),
+        config.group.clone(),
+        DEFAULT_GROUP.to_string(),
+    );
+    let main_class = format!("{}.{}", group, class_name);
+
+    let language_name = config::resolve(
+        language.map(str::to_string),
+        config.language.clone(),
+        DEFAULT_LANGUAGE.to_string(),
+    );
+    let language = templates::parse_language(&language_name)?;
+
+    let license = license
+        .map(str::to_string)
+        .or_else(|| config.license.clone());
+    let minecraft_version = minecraft_version
+        .map(str::to_string)
+        .or_else(|| config.minecraft_version.clone());
+
+    // The caller's own templates take precedence over same-named ones from
+    // config.json, the same way an explicit argument wins above.
+    let mut custom_templates = custom_templates.clone();
+    for (name, template) in &config.templates {
+        custom_templates
+            .entry(name.clone())
+            .or_insert_with(|| template.clone());
+    }
+
+    Ok(ScaffoldOptions {
+        language,
+        main_class,
+        license,
+        minecraft_version,
+        custom_templates,
+    })
+}
+
 #[derive(Debug)]
 pub struct Error {
     message: String,
@@ -48,7 +137,32 @@ impl From<serde_json::Error> for Error {
     }
 }
 
-fn update_mod_config(path: &Path, mod_id: &str, main_class: &str, name: &str) -> Result<(), Error> {
+impl From<templates::Error> for Error {
+    fn from(error: templates::Error) -> Self {
+        Error {
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<github::Error> for Error {
+    fn from(error: github::Error) -> Self {
+        Error {
+            message: error.to_string(),
+        }
+    }
+}
+
+fn update_mod_config(
+    path: &Path,
+    mod_id: &str,
+    main_class: &str,
+    name: &str,
+    author: Option<&str>,
+    license: Option<&str>,
+    minecraft_version: Option<&str>,
+    loader_version: Option<&str>,
+) -> Result<(), Error> {
     let config_path = path.join("src/main/resources/fabric.mod.json");
     let mut config: serde_json::Value =
         serde_json::from_str(&std::fs::read_to_string(&config_path)?)?;
@@ -56,10 +170,77 @@ fn update_mod_config(path: &Path, mod_id: &str, main_class: &str, name: &str) ->
     config["name"] = serde_json::Value::String(name.to_string());
     config["description"] = serde_json::Value::String("".to_string());
     config["entrypoints"]["main"] = serde_json::Value::String(main_class.to_string());
+    if let Some(author) = author {
+        config["authors"] = serde_json::Value::Array(vec![serde_json::Value::String(
+            author.to_string(),
+        )]);
+    }
+    if let Some(license) = license {
+        config["license"] = serde_json::Value::String(license.to_string());
+    }
+    if let Some(minecraft_version) = minecraft_version {
+        config["depends"]["minecraft"] =
+            serde_json::Value::String(format!(">={}", minecraft_version));
+    }
+    if let Some(loader_version) = loader_version {
+        config["depends"]["fabricloader"] =
+            serde_json::Value::String(format!(">={}", loader_version));
+    }
     std::fs::write(config_path, serde_json::to_string_pretty(&config)?)?;
     Ok(())
 }
 
+// Falls back to USER/USERNAME when git has no identity configured.
+fn author_identity(context: &git::Context) -> Option<String> {
+    let name = context
+        .git(&["config", "user.name"])
+        .ok()
+        .map(|output| output.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .or_else(|| std::env::var("USER").ok())
+        .or_else(|| std::env::var("USERNAME").ok());
+
+    let email = context
+        .git(&["config", "user.email"])
+        .ok()
+        .map(|output| output.trim().to_string())
+        .filter(|value| !value.is_empty());
+
+    format_identity(name, email)
+}
+
+fn format_identity(name: Option<String>, email: Option<String>) -> Option<String> {
+    let name = name?;
+    match email {
+        Some(email) => Some(format!("{} <{}>", name, email)),
+        None => Some(name),
+    }
+}
+
+fn materialize_license(path: &Path, license: &str, author: Option<&str>) -> Result<(), Error> {
+    let license_path = path.join("LICENSE");
+    if license_path.exists() {
+        return Ok(());
+    }
+
+    let template = match license {
+        "MIT" => include_str!("../assets/licenses/MIT.txt"),
+        "Apache-2.0" => include_str!("../assets/licenses/Apache-2.0.txt"),
+        other => {
+            return Err(Error {
+                message: format!(
+                    "no bundled LICENSE template for `{}`; fabric.mod.json now lists it, but no LICENSE file was written",
+                    other
+                ),
+            })
+        }
+    };
+
+    let text = template.replace("{{author}}", author.unwrap_or("the copyright holder"));
+    std::fs::write(license_path, text)?;
+    Ok(())
+}
+
 fn update_mixin_config(path: &Path, mod_id: &str) -> Result<(), Error> {
     let config_path = path.join(format!("src/main/resources/{}.mixins.json", mod_id));
     let mut config: serde_json::Value =
@@ -69,71 +250,265 @@ fn update_mixin_config(path: &Path, mod_id: &str) -> Result<(), Error> {
     Ok(())
 }
 
-fn update_gradle_properties(path: &Path, group: &str, base_name: &str) -> Result<(), Error> {
+fn update_gradle_properties(
+    path: &Path,
+    group: &str,
+    base_name: &str,
+    versions: &Versions,
+) -> Result<(), Error> {
     let config_path = path.join("gradle.properties");
     let mut config = std::fs::read_to_string(&config_path)?;
     config = config.replace("com.example", group);
     config = config.replace("fabric-example-mod", base_name);
+
+    for (key, value) in [
+        ("minecraft_version", &versions.minecraft_version),
+        ("yarn_mappings", &versions.yarn_mappings),
+        ("loader_version", &versions.loader_version),
+        ("fabric_version", &versions.fabric_api_version),
+    ] {
+        if let Some(value) = value {
+            config = set_gradle_property(&config, key, value);
+        }
+    }
+
     std::fs::write(config_path, config)?;
     Ok(())
 }
 
+// Matches the property key rather than doing a blind substring replace, so
+// values aren't clobbered by unrelated lines containing the same text.
+fn set_gradle_property(contents: &str, key: &str, value: &str) -> String {
+    let newline = if contents.contains("\r\n") { "\r\n" } else { "\n" };
+
+    let mut replaced = false;
+    let mut lines: Vec<String> = contents
+        .lines()
+        .map(|line| match line.split_once('=') {
+            Some((existing_key, _)) if existing_key.trim() == key => {
+                replaced = true;
+                format!("{}={}", key, value)
+            }
+            _ => line.to_string(),
+        })
+        .collect();
+
+    if !replaced {
+        lines.push(format!("{}={}", key, value));
+    }
+
+    lines.join(newline) + newline
+}
+
+// Returns None when the caller didn't request a version, or when `latest`
+// couldn't be resolved (e.g. offline) — the template's existing value is
+// left untouched either way.
+fn resolve_version(endpoint: &str, requested: Option<&str>) -> Option<String> {
+    match requested {
+        None => None,
+        Some(LATEST) => latest_stable_version(endpoint),
+        Some(version) => Some(version.to_string()),
+    }
+}
+
+fn latest_stable_version(endpoint: &str) -> Option<String> {
+    let url = format!("https://meta.fabricmc.net/v2/versions/{}", endpoint);
+    let versions: serde_json::Value = ureq::get(&url).call().ok()?.into_json().ok()?;
+    select_stable_version(versions.as_array()?)
+}
+
+// Split out of latest_stable_version so the selection logic can be tested
+// without a network call. Falls back to the first entry if none are stable.
+fn select_stable_version(versions: &[serde_json::Value]) -> Option<String> {
+    versions
+        .iter()
+        .find(|entry| entry["stable"].as_bool().unwrap_or(false))
+        .or_else(|| versions.first())
+        .and_then(|entry| entry["version"].as_str())
+        .map(str::to_string)
+}
+
+fn template_cache_dir() -> Result<PathBuf, Error> {
+    dirs::cache_dir()
+        .map(|dir| dir.join("new-fabric-mod").join("templates"))
+        .ok_or_else(|| Error {
+            message: "could not determine the platform cache directory".to_string(),
+        })
+}
+
+// Hash the URL rather than collapsing punctuation to a single separator, so
+// two URLs differing only in separators (a-b vs a_b) don't collide.
+fn template_cache_key(template_url: &str) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    template_url.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+fn ensure_template_cached(template_url: &str, refresh: bool) -> Result<PathBuf, Error> {
+    ensure_template_cached_in(&template_cache_dir()?, template_url, refresh)
+}
+
+// Like ensure_template_cached, but rooted at cache_dir so tests can exercise
+// reuse/refresh without touching the real cache.
+fn ensure_template_cached_in(
+    cache_dir: &Path,
+    template_url: &str,
+    refresh: bool,
+) -> Result<PathBuf, Error> {
+    let cached_path = cache_dir.join(template_cache_key(template_url));
+
+    if refresh && cached_path.exists() {
+        std::fs::remove_dir_all(&cached_path)?;
+    }
+
+    if !cached_path.exists() {
+        std::fs::create_dir_all(cached_path.parent().unwrap())?;
+        let global = git::Context::new(&None)?;
+        global.git(&["clone", template_url, cached_path.to_str().unwrap()])?;
+    }
+
+    Ok(cached_path)
+}
+
+// Skips the cache's own .git directory, so dst starts out without version
+// control.
+fn copy_template(src: &Path, dst: &Path) -> Result<(), Error> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        if entry.file_name() == ".git" {
+            continue;
+        }
+
+        let dst_path = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &dst_path)?;
+        } else {
+            std::fs::copy(entry.path(), &dst_path)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn create_mod(
     path: &Path,
     mod_id: &str,
     language: &Language,
     main_class: &str,
     name: &str,
+    vcs: Vcs,
+    refresh_template_cache: bool,
+    license: Option<&str>,
+    versions: &Versions,
+    template: Option<&str>,
+    custom_templates: &HashMap<String, CustomTemplate>,
+    publish_to_github: bool,
 ) -> Result<(), Error> {
-    // Clone the Kotlin example mod
-    let template_url = match language {
-        Language::Kotlin => "https://github.com/clabe45/fabric-example-mod-kotlin",
-        Language::Java => "https://github.com/FabricMC/fabric-example-mod",
-    };
-    let global = git::Context::new(&None)?;
-    global.git(&["clone", template_url, path.to_str().unwrap()])?;
+    if publish_to_github && vcs == Vcs::None {
+        return Err(Error {
+            message: "--publish-to-github requires the git repository created by vcs = Git"
+                .to_string(),
+        });
+    }
 
-    // Remove the .git directory
-    let git_dir = path.join(".git");
-    std::fs::remove_dir_all(git_dir)?;
+    // Resolve the template to scaffold from, and copy it from the local cache,
+    // cloning it first if needed
+    let template = templates::resolve(template, language, custom_templates)?;
+    let cached_template = ensure_template_cached(&template.source, refresh_template_cache)?;
+    copy_template(&cached_template, path)?;
+
+    // Initialize the git repository, unless the caller manages VCS themselves
+    let repo = match vcs {
+        Vcs::Git => {
+            let repo = git::Context::new(&Some(path))?;
+            repo.git(&["init"])?;
+            Some(repo)
+        }
+        Vcs::None => None,
+    };
 
-    // Re-initialize the git repository
-    let repo = git::Context::new(&Some(path))?;
-    repo.git(&["init"])?;
+    // Derive author identity from the global git config, if any
+    let global = git::Context::new(&None)?;
+    let author = author_identity(&global);
+
+    // Resolve requested versions, leaving unrequested ones untouched
+    let minecraft_version = resolve_version("game", versions.minecraft_version.as_deref());
+    let yarn_mappings = resolve_version("yarn", versions.yarn_mappings.as_deref());
+    let loader_version = resolve_version("loader", versions.loader_version.as_deref());
+    // Fabric API isn't published on meta.fabricmc.net, so `latest` can't be
+    // resolved for it; fall back to the template's existing value instead.
+    let fabric_api_version = match versions.fabric_api_version.as_deref() {
+        Some(LATEST) => None,
+        other => other.map(str::to_string),
+    };
 
     // Rename the package
-    let old_package = "net.fabricmc.example";
     let new_package = main_class[..main_class.rfind('.').unwrap()].to_string();
-    refactor::rename_package(path, language, &old_package, &new_package)?;
+    refactor::rename_package(path, &template.language, &template.old_package, &new_package)?;
 
     // Rename the class
     let old_class = format!("{}.ExampleMod", &new_package);
     let new_class = main_class;
-    refactor::rename_class(path, language, &old_class, &new_class)?;
+    refactor::rename_class(path, &template.language, &old_class, &new_class)?;
 
     // Update the mixins config
     std::fs::rename(
-        path.join("src/main/resources/modid.mixins.json"),
+        path.join(format!("src/main/resources/{}", template.old_mixin_config)),
         path.join(format!("src/main/resources/{}.mixins.json", mod_id)),
     )?;
     update_mixin_config(path, mod_id)?;
 
     // Update the mod config
-    update_mod_config(path, mod_id, main_class, name)?;
+    update_mod_config(
+        path,
+        mod_id,
+        main_class,
+        name,
+        author.as_deref(),
+        license,
+        minecraft_version.as_deref(),
+        loader_version.as_deref(),
+    )?;
+
+    // Materialize the LICENSE file, if a license was requested
+    if let Some(license) = license {
+        materialize_license(path, license, author.as_deref())?;
+    }
 
     // Update gradle.properties
     let group = &new_package[..new_package.rfind('.').unwrap()].to_string();
     let base_name = &new_package[new_package.rfind('.').unwrap() + 1..].to_string();
-    update_gradle_properties(path, &group, &base_name)?;
+    update_gradle_properties(
+        path,
+        &group,
+        &base_name,
+        &Versions {
+            minecraft_version,
+            yarn_mappings,
+            loader_version,
+            fabric_api_version,
+        },
+    )?;
+
+    // Create a remote GitHub repository and push the scaffolded mod to it,
+    // if requested. The vcs/publish_to_github compatibility check at the top
+    // of this function guarantees `repo` is `Some` here.
+    if publish_to_github {
+        github::publish(path, mod_id, repo.as_ref().unwrap())?;
+    }
 
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use rstest::rstest;
 
-    use crate::{code::language::Language, fabric};
+    use crate::{code::language::Language, config, fabric};
 
     #[rstest]
     #[case(Language::Java)]
@@ -147,6 +522,13 @@ mod tests {
             &language,
             "net.fabricmc.example.ExampleMod",
             "Example Mod",
+            fabric::Vcs::Git,
+            false,
+            None,
+            &fabric::Versions::default(),
+            None,
+            &HashMap::new(),
+            false,
         )
         .unwrap();
 
@@ -166,6 +548,13 @@ mod tests {
             &language,
             "net.fabricmc.example2.ExampleMod2",
             "Example Mod 2",
+            fabric::Vcs::Git,
+            false,
+            None,
+            &fabric::Versions::default(),
+            None,
+            &HashMap::new(),
+            false,
         )
         .unwrap();
 
@@ -189,6 +578,13 @@ mod tests {
             &language,
             "net.fabricmc.example3.ExampleMod2",
             "Example Mod 2",
+            fabric::Vcs::Git,
+            false,
+            None,
+            &fabric::Versions::default(),
+            None,
+            &HashMap::new(),
+            false,
         )
         .unwrap();
 
@@ -208,6 +604,13 @@ mod tests {
             &language,
             "net.fabricmc.example2.ExampleMod2",
             "Example Mod 2",
+            fabric::Vcs::Git,
+            false,
+            None,
+            &fabric::Versions::default(),
+            None,
+            &HashMap::new(),
+            false,
         )
         .unwrap();
 
@@ -237,6 +640,13 @@ mod tests {
             &language,
             "net.fabricmc.example2.ExampleMod2",
             "Example Mod 2",
+            fabric::Vcs::Git,
+            false,
+            None,
+            &fabric::Versions::default(),
+            None,
+            &HashMap::new(),
+            false,
         )
         .unwrap();
 
@@ -245,4 +655,349 @@ mod tests {
         assert!(contents.contains("net.fabricmc"));
         assert!(contents.contains("example2"));
     }
+
+    #[rstest]
+    #[case(Language::Java)]
+    #[case(Language::Kotlin)]
+    fn test_create_mod_writes_license(#[case] language: Language) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("test_create_mod_writes_license");
+        fabric::create_mod(
+            &path,
+            "example-mod2",
+            &language,
+            "net.fabricmc.example2.ExampleMod2",
+            "Example Mod 2",
+            fabric::Vcs::Git,
+            false,
+            Some("MIT"),
+            &fabric::Versions::default(),
+            None,
+            &HashMap::new(),
+            false,
+        )
+        .unwrap();
+
+        let license = path.join("LICENSE");
+        assert!(license.exists());
+
+        let mod_json = path.join("src/main/resources/fabric.mod.json");
+        let contents = std::fs::read_to_string(mod_json).unwrap();
+        let config: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(config["license"], "MIT");
+    }
+
+    #[rstest]
+    #[case(Language::Java)]
+    #[case(Language::Kotlin)]
+    fn test_create_mod_pins_versions(#[case] language: Language) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("test_create_mod_pins_versions");
+        fabric::create_mod(
+            &path,
+            "example-mod2",
+            &language,
+            "net.fabricmc.example2.ExampleMod2",
+            "Example Mod 2",
+            fabric::Vcs::Git,
+            false,
+            None,
+            &fabric::Versions {
+                minecraft_version: Some("1.20.1".to_string()),
+                yarn_mappings: Some("1.20.1+build.10".to_string()),
+                loader_version: Some("0.15.0".to_string()),
+                fabric_api_version: Some("0.92.0+1.20.1".to_string()),
+            },
+            None,
+            &HashMap::new(),
+            false,
+        )
+        .unwrap();
+
+        let gradle_properties = path.join("gradle.properties");
+        let contents = std::fs::read_to_string(gradle_properties).unwrap();
+        assert!(contents.contains("minecraft_version=1.20.1"));
+        assert!(contents.contains("yarn_mappings=1.20.1+build.10"));
+        assert!(contents.contains("loader_version=0.15.0"));
+        assert!(contents.contains("fabric_version=0.92.0+1.20.1"));
+
+        let mod_json = path.join("src/main/resources/fabric.mod.json");
+        let contents = std::fs::read_to_string(mod_json).unwrap();
+        let config: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(config["depends"]["minecraft"], ">=1.20.1");
+        assert_eq!(config["depends"]["fabricloader"], ">=0.15.0");
+    }
+
+    #[test]
+    fn test_create_mod_rejects_publish_to_github_without_git_before_scaffolding() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir
+            .path()
+            .join("test_create_mod_rejects_publish_to_github_without_git");
+        let result = fabric::create_mod(
+            &path,
+            "example-mod",
+            &Language::Java,
+            "net.fabricmc.example.ExampleMod",
+            "Example Mod",
+            fabric::Vcs::None,
+            false,
+            None,
+            &fabric::Versions::default(),
+            None,
+            &HashMap::new(),
+            true,
+        );
+
+        assert!(result.is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_format_identity_combines_name_and_email() {
+        assert_eq!(
+            super::format_identity(Some("Ada".to_string()), Some("ada@example.com".to_string())),
+            Some("Ada <ada@example.com>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_identity_falls_back_to_name_only() {
+        assert_eq!(
+            super::format_identity(Some("Ada".to_string()), None),
+            Some("Ada".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_identity_skips_gracefully_with_no_identity_configured() {
+        assert_eq!(super::format_identity(None, None), None);
+        assert_eq!(
+            super::format_identity(None, Some("ada@example.com".to_string())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_template_cache_key_distinguishes_urls_differing_only_in_separators() {
+        let a = super::template_cache_key("https://github.com/a-b/c");
+        let b = super::template_cache_key("https://github.com/a_b/c");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_ensure_template_cached_in_reuses_an_existing_clone() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let template_url = "https://github.com/FabricMC/fabric-example-mod";
+
+        let first =
+            super::ensure_template_cached_in(cache_dir.path(), template_url, false).unwrap();
+        std::fs::write(first.join("MARKER"), "present").unwrap();
+
+        let second =
+            super::ensure_template_cached_in(cache_dir.path(), template_url, false).unwrap();
+
+        assert_eq!(first, second);
+        assert!(second.join("MARKER").exists());
+    }
+
+    #[test]
+    fn test_ensure_template_cached_in_refreshes_when_requested() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let template_url = "https://github.com/FabricMC/fabric-example-mod";
+
+        let first =
+            super::ensure_template_cached_in(cache_dir.path(), template_url, false).unwrap();
+        std::fs::write(first.join("MARKER"), "present").unwrap();
+
+        let second =
+            super::ensure_template_cached_in(cache_dir.path(), template_url, true).unwrap();
+
+        assert_eq!(first, second);
+        assert!(!second.join("MARKER").exists());
+    }
+
+    #[test]
+    fn test_resolve_options_prefers_explicit_args_over_config_over_default() {
+        let mut config = config::Config::default();
+        config.group = Some("com.configured".to_string());
+        config.language = Some("kotlin".to_string());
+        config.license = Some("Apache-2.0".to_string());
+        config.minecraft_version = Some("1.19.2".to_string());
+
+        let explicit = fabric::resolve_options(
+            "ExampleMod",
+            Some("com.explicit"),
+            Some("java"),
+            Some("MIT"),
+            Some("1.20.1"),
+            &HashMap::new(),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(explicit.main_class, "com.explicit.ExampleMod");
+        assert_eq!(explicit.language, Language::Java);
+        assert_eq!(explicit.license, Some("MIT".to_string()));
+        assert_eq!(explicit.minecraft_version, Some("1.20.1".to_string()));
+
+        let from_config =
+            fabric::resolve_options("ExampleMod", None, None, None, None, &HashMap::new(), &config)
+                .unwrap();
+        assert_eq!(from_config.main_class, "com.configured.ExampleMod");
+        assert_eq!(from_config.language, Language::Kotlin);
+        assert_eq!(from_config.license, Some("Apache-2.0".to_string()));
+        assert_eq!(from_config.minecraft_version, Some("1.19.2".to_string()));
+
+        let defaults = fabric::resolve_options(
+            "ExampleMod",
+            None,
+            None,
+            None,
+            None,
+            &HashMap::new(),
+            &config::Config::default(),
+        )
+        .unwrap();
+        assert_eq!(defaults.main_class, "com.example.ExampleMod");
+        assert_eq!(defaults.language, Language::Java);
+        assert_eq!(defaults.license, None);
+        assert_eq!(defaults.minecraft_version, None);
+    }
+
+    #[test]
+    fn test_resolve_options_merges_config_templates_under_caller_templates() {
+        let mut config = config::Config::default();
+        config.templates.insert(
+            "kotlin-dsl".to_string(),
+            config::CustomTemplate {
+                source: "https://example.com/configured".to_string(),
+                language: "kotlin".to_string(),
+                old_package: "net.fabricmc.example".to_string(),
+                old_mixin_config: "modid.mixins.json".to_string(),
+            },
+        );
+
+        let mut caller_templates = HashMap::new();
+        caller_templates.insert(
+            "kotlin-dsl".to_string(),
+            config::CustomTemplate {
+                source: "https://example.com/overridden".to_string(),
+                language: "kotlin".to_string(),
+                old_package: "net.fabricmc.example".to_string(),
+                old_mixin_config: "modid.mixins.json".to_string(),
+            },
+        );
+
+        let options = fabric::resolve_options(
+            "ExampleMod",
+            None,
+            None,
+            None,
+            None,
+            &caller_templates,
+            &config,
+        )
+        .unwrap();
+
+        assert_eq!(
+            options.custom_templates["kotlin-dsl"].source,
+            "https://example.com/overridden"
+        );
+    }
+
+    #[test]
+    fn test_create_mod_scaffolds_from_resolved_options() {
+        let mut config = config::Config::default();
+        config.license = Some("MIT".to_string());
+        config.minecraft_version = Some("1.20.1".to_string());
+
+        let options = fabric::resolve_options(
+            "ExampleMod3",
+            None,
+            Some("java"),
+            None,
+            None,
+            &HashMap::new(),
+            &config,
+        )
+        .unwrap();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir
+            .path()
+            .join("test_create_mod_scaffolds_from_resolved_options");
+        fabric::create_mod(
+            &path,
+            "example-mod3",
+            &options.language,
+            &options.main_class,
+            "Example Mod 3",
+            fabric::Vcs::Git,
+            false,
+            options.license.as_deref(),
+            &fabric::Versions {
+                minecraft_version: options.minecraft_version.clone(),
+                ..fabric::Versions::default()
+            },
+            None,
+            &options.custom_templates,
+            false,
+        )
+        .unwrap();
+
+        let mod_json = path.join("src/main/resources/fabric.mod.json");
+        let contents = std::fs::read_to_string(mod_json).unwrap();
+        let config: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(config["license"], "MIT");
+        assert_eq!(config["depends"]["minecraft"], ">=1.20.1");
+    }
+
+    #[test]
+    fn test_materialize_license_rejects_an_unsupported_spdx_id() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let result = super::materialize_license(temp_dir.path(), "GPL-3.0", None);
+
+        assert!(result.is_err());
+        assert!(!temp_dir.path().join("LICENSE").exists());
+    }
+
+    #[test]
+    fn test_select_stable_version_empty_array() {
+        assert_eq!(super::select_stable_version(&[]), None);
+    }
+
+    #[test]
+    fn test_select_stable_version_falls_back_to_first_entry_with_no_stable() {
+        let versions: Vec<serde_json::Value> = serde_json::from_str(
+            r#"[{"version": "1.20-rc1", "stable": false}, {"version": "1.19.4", "stable": false}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            super::select_stable_version(&versions),
+            Some("1.20-rc1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_select_stable_version_picks_the_stable_entry() {
+        let versions: Vec<serde_json::Value> = serde_json::from_str(
+            r#"[{"version": "1.20-rc1", "stable": false}, {"version": "1.19.4", "stable": true}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            super::select_stable_version(&versions),
+            Some("1.19.4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_set_gradle_property_preserves_crlf_line_endings() {
+        let contents = "org.gradle.jvmargs=-Xmx1G\r\nminecraft_version=1.19.4\r\n";
+        let updated = super::set_gradle_property(contents, "minecraft_version", "1.20.1");
+
+        assert_eq!(
+            updated,
+            "org.gradle.jvmargs=-Xmx1G\r\nminecraft_version=1.20.1\r\n"
+        );
+    }
 }
@@ -0,0 +1,169 @@
+use std::path::Path;
+
+use crate::{config, git};
+
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl From<git::Error> for Error {
+    fn from(error: git::Error) -> Self {
+        Error {
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error {
+            message: error.to_string(),
+        }
+    }
+}
+
+const GITIGNORE: &str = "\
+# Gradle
+.gradle/
+build/
+
+# IDE
+.idea/
+*.iml
+.vscode/
+";
+
+fn token() -> Option<String> {
+    std::env::var("GITHUB_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+        .or_else(|| config::load().ok().and_then(|config| config.github_token))
+}
+
+fn create_repository(name: &str, token: &str) -> Result<String, Error> {
+    let response = ureq::post("https://api.github.com/user/repos")
+        .set("Authorization", &format!("Bearer {}", token))
+        .set("Accept", "application/vnd.github+json")
+        .send_json(serde_json::json!({ "name": name }))
+        .map_err(|error| Error {
+            message: format!("failed to create GitHub repository: {}", error),
+        })?;
+
+    let body: serde_json::Value = response.into_json().map_err(|error| Error {
+        message: error.to_string(),
+    })?;
+
+    body["clone_url"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| Error {
+            message: "GitHub API response did not include a clone_url".to_string(),
+        })
+}
+
+// One-off base64 encoder for push_auth_header's Basic credential, rather
+// than a dependency, since this is the only call site.
+fn base64_encode(input: &str) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let bytes = input.as_bytes();
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        out.push(ALPHABET[((triple >> 18) & 0x3F) as usize] as char);
+        out.push(ALPHABET[((triple >> 12) & 0x3F) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            ALPHABET[((triple >> 6) & 0x3F) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            ALPHABET[(triple & 0x3F) as usize] as char
+        } else {
+            '='
+        });
+    }
+
+    out
+}
+
+// Scopes the credential to one git invocation via -c, rather than embedding
+// it in the origin remote URL, so it doesn't linger in .git/config.
+fn push_auth_header(token: &str) -> String {
+    format!(
+        "http.extraHeader=Authorization: Basic {}",
+        base64_encode(&format!("x-access-token:{}", token))
+    )
+}
+
+// context must already have a local repository initialized (i.e. create_mod
+// was called with Vcs::Git). The local commit happens before the remote
+// repository is created, so a failure there never leaves a remote behind
+// with nothing pushed to it.
+pub fn publish(path: &Path, repo_name: &str, context: &git::Context) -> Result<(), Error> {
+    let token = token().ok_or_else(|| Error {
+        message: "no GitHub token found; set GITHUB_TOKEN or github_token in config.json"
+            .to_string(),
+    })?;
+
+    if !path.join(".gitignore").exists() {
+        std::fs::write(path.join(".gitignore"), GITIGNORE)?;
+    }
+
+    context.git(&["add", "-A"])?;
+    context.git(&["commit", "-m", "Initial commit"])?;
+    context.git(&["branch", "-M", "main"])?;
+
+    let clone_url = create_repository(repo_name, &token)?;
+    context.git(&["remote", "add", "origin", &clone_url])?;
+
+    context
+        .git(&[
+            "-c",
+            &push_auth_header(&token),
+            "push",
+            "-u",
+            "origin",
+            "main",
+        ])
+        .map_err(|error| Error {
+            message: format!(
+                "created GitHub repository {} but failed to push to it: {}",
+                clone_url, error
+            ),
+        })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode_matches_known_values() {
+        assert_eq!(
+            base64_encode("x-access-token:ghp_example"),
+            "eC1hY2Nlc3MtdG9rZW46Z2hwX2V4YW1wbGU="
+        );
+        assert_eq!(base64_encode(""), "");
+    }
+
+    #[test]
+    fn test_push_auth_header_embeds_a_basic_credential() {
+        let header = push_auth_header("ghp_example");
+        assert!(header.starts_with("http.extraHeader=Authorization: Basic "));
+        assert!(!header.contains("ghp_example"));
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::{code::language::Language, config::CustomTemplate};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    pub source: String,
+    pub language: Language,
+    pub old_package: String,
+    pub old_mixin_config: String,
+}
+
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+fn clone_language(language: &Language) -> Language {
+    match language {
+        Language::Java => Language::Java,
+        Language::Kotlin => Language::Kotlin,
+    }
+}
+
+pub(crate) fn parse_language(language: &str) -> Result<Language, Error> {
+    match language {
+        "java" => Ok(Language::Java),
+        "kotlin" => Ok(Language::Kotlin),
+        other => Err(Error {
+            message: format!("unknown template language `{}`", other),
+        }),
+    }
+}
+
+// Inverse of parse_language.
+pub(crate) fn language_name(language: &Language) -> &'static str {
+    match language {
+        Language::Java => "java",
+        Language::Kotlin => "kotlin",
+    }
+}
+
+pub fn builtin(language: &Language) -> Template {
+    let source = match language {
+        Language::Kotlin => "https://github.com/clabe45/fabric-example-mod-kotlin",
+        Language::Java => "https://github.com/FabricMC/fabric-example-mod",
+    };
+
+    Template {
+        source: source.to_string(),
+        language: clone_language(language),
+        old_package: "net.fabricmc.example".to_string(),
+        old_mixin_config: "modid.mixins.json".to_string(),
+    }
+}
+
+// Falls back to the built-in template for `language` with no reference. A
+// reference that isn't a registered name is rejected rather than guessed at,
+// since a third-party template has no reason to follow the built-in
+// templates' net.fabricmc.example/modid.mixins.json conventions.
+pub fn resolve(
+    reference: Option<&str>,
+    language: &Language,
+    custom_templates: &HashMap<String, CustomTemplate>,
+) -> Result<Template, Error> {
+    let reference = match reference {
+        Some(reference) => reference,
+        None => return Ok(builtin(language)),
+    };
+
+    let custom = custom_templates.get(reference).ok_or_else(|| Error {
+        message: format!(
+            "unknown template `{}`; register it in config.json's `templates` first",
+            reference
+        ),
+    })?;
+
+    Ok(Template {
+        source: custom.source.clone(),
+        language: parse_language(&custom.language)?,
+        old_package: custom.old_package.clone(),
+        old_mixin_config: custom.old_mixin_config.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_falls_back_to_builtin_with_no_reference() {
+        let template = resolve(None, &Language::Kotlin, &HashMap::new()).unwrap();
+        assert_eq!(template, builtin(&Language::Kotlin));
+    }
+
+    #[test]
+    fn test_resolve_looks_up_registered_name() {
+        let mut custom_templates = HashMap::new();
+        custom_templates.insert(
+            "kotlin-dsl".to_string(),
+            CustomTemplate {
+                source: "https://example.com/template".to_string(),
+                language: "kotlin".to_string(),
+                old_package: "com.example.template".to_string(),
+                old_mixin_config: "template.mixins.json".to_string(),
+            },
+        );
+
+        let template = resolve(Some("kotlin-dsl"), &Language::Java, &custom_templates).unwrap();
+
+        assert_eq!(template.source, "https://example.com/template");
+        assert_eq!(template.language, Language::Kotlin);
+        assert_eq!(template.old_package, "com.example.template");
+        assert_eq!(template.old_mixin_config, "template.mixins.json");
+    }
+
+    #[test]
+    fn test_resolve_rejects_an_unregistered_reference() {
+        assert!(resolve(
+            Some("https://example.com/third-party"),
+            &Language::Java,
+            &HashMap::new(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_language_name_round_trips_through_parse_language() {
+        for language in [Language::Java, Language::Kotlin] {
+            assert_eq!(parse_language(language_name(&language)).unwrap(), language);
+        }
+    }
+
+    #[test]
+    fn test_resolve_rejects_unknown_registered_language() {
+        let mut custom_templates = HashMap::new();
+        custom_templates.insert(
+            "broken".to_string(),
+            CustomTemplate {
+                source: "https://example.com/template".to_string(),
+                language: "rust".to_string(),
+                old_package: "net.fabricmc.example".to_string(),
+                old_mixin_config: "modid.mixins.json".to_string(),
+            },
+        );
+
+        assert!(resolve(Some("broken"), &Language::Java, &custom_templates).is_err());
+    }
+}